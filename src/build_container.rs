@@ -0,0 +1,161 @@
+//!
+//! Container-based cross-compilation for musl/aarch64/riscv targets.
+//!
+
+use std::path::Path;
+use std::process::Command;
+
+use crate::build_type::BuildType;
+
+/// The container engines supported for cross builds, tried in this order.
+const CONTAINER_ENGINES: [&str; 2] = ["docker", "podman"];
+
+/// The environment variable selecting a cross-compilation target triple. When
+/// it names one of `SUPPORTED_TARGETS`, `build_dispatch::build` dispatches to
+/// this module's container-based build instead of building for the host.
+pub const CROSS_TARGET_ENV_VAR: &str = "ZKEVM_LLVM_CROSS_TARGET";
+
+///
+/// A supported cross-compilation target triple and its matching container image
+/// and CMake toolchain file.
+///
+pub struct CrossTarget {
+    /// The target triple, e.g. `aarch64-unknown-linux-musl`.
+    pub triple: &'static str,
+    /// The container image providing the cross toolchain and sysroot.
+    pub image: &'static str,
+    /// The CMake toolchain file path inside the container.
+    pub toolchain_file: &'static str,
+}
+
+/// The matrix of triples this subsystem can cross-build LLVM for.
+pub const SUPPORTED_TARGETS: [CrossTarget; 3] = [
+    CrossTarget {
+        triple: "aarch64-unknown-linux-musl",
+        image: "ghcr.io/matter-labs/era-compiler-llvm-cross:aarch64-musl",
+        toolchain_file: "/opt/toolchains/aarch64-unknown-linux-musl.cmake",
+    },
+    CrossTarget {
+        triple: "riscv64-unknown-linux-gnu",
+        image: "ghcr.io/matter-labs/era-compiler-llvm-cross:riscv64-gnu",
+        toolchain_file: "/opt/toolchains/riscv64-unknown-linux-gnu.cmake",
+    },
+    CrossTarget {
+        triple: "x86_64-unknown-linux-musl",
+        image: "ghcr.io/matter-labs/era-compiler-llvm-cross:x86_64-musl",
+        toolchain_file: "/opt/toolchains/x86_64-unknown-linux-musl.cmake",
+    },
+];
+
+///
+/// Finds the cross-build configuration for `triple`, if supported.
+///
+pub fn find_target(triple: &str) -> Option<&'static CrossTarget> {
+    SUPPORTED_TARGETS
+        .iter()
+        .find(|target| target.triple == triple)
+}
+
+///
+/// Detects an available container engine, preferring `docker` and falling back
+/// to `podman`.
+///
+pub fn detect_engine() -> anyhow::Result<&'static str> {
+    for engine in CONTAINER_ENGINES {
+        if crate::utils::check_presence(engine).is_ok() {
+            return Ok(engine);
+        }
+    }
+    anyhow::bail!(
+        "None of the container engines {:?} are installed. Please install one.",
+        CONTAINER_ENGINES
+    )
+}
+
+///
+/// Runs the LLVM cmake/ninja build for `triple` inside a container, mounting the
+/// LLVM source, build, and target directories and selecting the matching
+/// sysroot/toolchain file.
+///
+pub fn build(
+    triple: &str,
+    build_type: BuildType,
+    llvm_module_llvm: &Path,
+    llvm_build_final: &Path,
+    llvm_target_final: &Path,
+) -> anyhow::Result<()> {
+    let target = find_target(triple).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Unsupported cross-compilation target `{triple}`. Supported targets: {}",
+            SUPPORTED_TARGETS
+                .iter()
+                .map(|target| target.triple)
+                .collect::<Vec<&str>>()
+                .join(", ")
+        )
+    })?;
+    let engine = detect_engine()?;
+
+    std::fs::create_dir_all(llvm_build_final)?;
+    std::fs::create_dir_all(llvm_target_final)?;
+
+    crate::utils::command(
+        Command::new(engine)
+            .args(["run", "--rm"])
+            .arg("-v")
+            .arg(format!(
+                "{}:/llvm-module:ro",
+                llvm_module_llvm.to_string_lossy()
+            ))
+            .arg("-v")
+            .arg(format!("{}:/llvm-build", llvm_build_final.to_string_lossy()))
+            .arg("-v")
+            .arg(format!(
+                "{}:/llvm-target",
+                llvm_target_final.to_string_lossy()
+            ))
+            .arg(target.image)
+            .args([
+                "cmake",
+                "-S",
+                "/llvm-module",
+                "-B",
+                "/llvm-build",
+                "-G",
+                "Ninja",
+                format!("-DCMAKE_TOOLCHAIN_FILE='{}'", target.toolchain_file).as_str(),
+                "-DCMAKE_INSTALL_PREFIX='/llvm-target'",
+                format!("-DCMAKE_BUILD_TYPE='{build_type}'").as_str(),
+                "-DLLVM_ENABLE_PROJECTS='lld'",
+            ])
+            .args(crate::platforms::shared::SHARED_BUILD_OPTS)
+            .args(if target.triple.contains("musl") {
+                Vec::new()
+            } else {
+                crate::platforms::shared::SHARED_BUILD_OPTS_NOT_MUSL.to_vec()
+            }),
+        "Cross-compilation container cmake",
+    )?;
+
+    crate::utils::command(
+        Command::new(engine)
+            .args(["run", "--rm"])
+            .arg("-v")
+            .arg(format!(
+                "{}:/llvm-module:ro",
+                llvm_module_llvm.to_string_lossy()
+            ))
+            .arg("-v")
+            .arg(format!("{}:/llvm-build", llvm_build_final.to_string_lossy()))
+            .arg("-v")
+            .arg(format!(
+                "{}:/llvm-target",
+                llvm_target_final.to_string_lossy()
+            ))
+            .arg(target.image)
+            .args(["ninja", "-C", "/llvm-build", "install"]),
+        "Cross-compilation container ninja install",
+    )?;
+
+    Ok(())
+}
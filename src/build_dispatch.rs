@@ -0,0 +1,79 @@
+//!
+//! Top-level build dispatch, routing a requested cross-compilation target to
+//! the container-based cross build or to the host's native platform builder.
+//!
+
+use std::collections::HashSet;
+
+use crate::build_type::BuildType;
+use crate::ld_flags::LdFlags;
+use crate::llvm_path::LLVMPath;
+use crate::platforms::Platform;
+
+///
+/// Resolves the cross-compilation target triple from an explicit CLI
+/// `--cross-target` flag or, failing that, the `ZKEVM_LLVM_CROSS_TARGET`
+/// environment variable. Mirrors `BuildStrategy::resolve`'s CLI-flag-then-env
+/// precedence. Returns `None` when building for the host natively.
+///
+pub fn resolve_cross_target(cli_flag: Option<&str>) -> Option<String> {
+    cli_flag
+        .map(str::to_string)
+        .or_else(|| std::env::var(crate::build_container::CROSS_TARGET_ENV_VAR).ok())
+}
+
+///
+/// Whether `target_triple` names one of `build_container`'s supported
+/// cross-compilation targets, as opposed to the host being built for natively.
+///
+pub fn is_cross_target(target_triple: Option<&str>) -> bool {
+    target_triple
+        .map(|triple| crate::build_container::find_target(triple).is_some())
+        .unwrap_or(false)
+}
+
+///
+/// Builds LLVM for `cross_target`: a container cross-build when it resolves to
+/// a supported cross-compilation triple, or the host's native platform build
+/// otherwise.
+///
+#[allow(clippy::too_many_arguments)]
+pub fn build(
+    cross_target: Option<&str>,
+    build_type: BuildType,
+    targets: HashSet<Platform>,
+    enable_tests: bool,
+    enable_coverage: bool,
+    extra_args: Vec<String>,
+    use_ccache: bool,
+    enable_assertions: bool,
+    strategy: Option<&str>,
+    ld_flags: &LdFlags,
+    force: bool,
+) -> anyhow::Result<()> {
+    let target_triple = resolve_cross_target(cross_target);
+
+    if is_cross_target(target_triple.as_deref()) {
+        let triple = target_triple.expect("is_cross_target only returns true with a triple");
+        return crate::build_container::build(
+            triple.as_str(),
+            build_type,
+            LLVMPath::llvm_module_llvm()?.as_path(),
+            LLVMPath::llvm_build_final()?.as_path(),
+            LLVMPath::llvm_target_final()?.as_path(),
+        );
+    }
+
+    crate::platforms::aarch64_macos::build(
+        build_type,
+        targets,
+        enable_tests,
+        enable_coverage,
+        extra_args,
+        use_ccache,
+        enable_assertions,
+        strategy,
+        ld_flags,
+        force,
+    )
+}
@@ -0,0 +1,185 @@
+//!
+//! The incremental build stamp, used to skip redundant LLVM rebuilds.
+//!
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::build_type::BuildType;
+use crate::platforms::Platform;
+
+/// The name of the stamp file written after a successful `ninja install`.
+pub const STAMP_FILE_NAME: &str = ".llvm-build-stamp";
+
+/// The environment variable forcing a rebuild regardless of the stamp.
+pub const FORCE_REBUILD_ENV_VAR: &str = "ZKEVM_LLVM_FORCE_REBUILD";
+
+/// The name of the lock file describing the LLVM reference to build.
+const LLVM_LOCK_FILE: &str = "LLVM.lock";
+
+///
+/// Reads and parses `LLVM.lock` from the current working directory.
+///
+pub fn load_lock() -> anyhow::Result<crate::Lock> {
+    let contents = std::fs::read_to_string(LLVM_LOCK_FILE)?;
+    let lock: crate::Lock = toml::from_str(contents.as_str())?;
+    Ok(lock)
+}
+
+///
+/// Resolves whether the stamp check should be bypassed, from an explicit CLI
+/// `--force` flag or, failing that, the `ZKEVM_LLVM_FORCE_REBUILD` environment
+/// variable. Mirrors `BuildStrategy::resolve`'s CLI-flag-then-env precedence.
+///
+pub fn resolve_force(cli_flag: bool) -> bool {
+    cli_flag || std::env::var(FORCE_REBUILD_ENV_VAR).is_ok()
+}
+
+///
+/// The set of inputs that affect the LLVM build output, hashed to produce a stamp
+/// that lets `build` skip `cmake`/`ninja` when nothing relevant has changed.
+///
+pub struct BuildInputs<'a> {
+    pub url: &'a str,
+    pub branch: &'a str,
+    pub reference: Option<&'a str>,
+    pub build_type: BuildType,
+    pub targets: &'a HashSet<Platform>,
+    pub enable_tests: bool,
+    pub enable_coverage: bool,
+    pub enable_assertions: bool,
+    pub use_ccache: bool,
+    pub extra_args: &'a [String],
+    pub ld_flags: &'a crate::ld_flags::LdFlags,
+}
+
+impl BuildInputs<'_> {
+    ///
+    /// Hashes the inputs into a stable, reproducible SHA-256 hex digest.
+    ///
+    fn hash(&self) -> String {
+        let mut targets = self
+            .targets
+            .iter()
+            .map(|platform| platform.to_string())
+            .collect::<Vec<String>>();
+        targets.sort();
+        let ld_flag_args = self.ld_flags.to_cmake_args();
+
+        let canonical = format!(
+            "url={}\nbranch={}\nref={}\nbuild_type={}\ntargets={}\nenable_tests={}\nenable_coverage={}\nenable_assertions={}\nuse_ccache={}\nextra_args={}\nld_flags={}\n",
+            self.url,
+            self.branch,
+            self.reference.unwrap_or(""),
+            self.build_type,
+            encode_list(targets.iter().map(String::as_str)),
+            self.enable_tests,
+            self.enable_coverage,
+            self.enable_assertions,
+            self.use_ccache,
+            encode_list(self.extra_args.iter().map(String::as_str)),
+            encode_list(ld_flag_args.iter().map(String::as_str)),
+        );
+
+        let mut hasher = Sha256::new();
+        hasher.update(canonical.as_bytes());
+        hex::encode(hasher.finalize())
+    }
+}
+
+///
+/// Encodes a list of strings unambiguously by length-prefixing each element, so
+/// that distinct lists (e.g. `["-a b"]` vs `["-a", "b"]`) never collide in the
+/// hashed canonical string.
+///
+fn encode_list<'a>(items: impl Iterator<Item = &'a str>) -> String {
+    let mut encoded = String::new();
+    for item in items {
+        encoded.push_str(item.len().to_string().as_str());
+        encoded.push(':');
+        encoded.push_str(item);
+        encoded.push(';');
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::encode_list;
+
+    #[test]
+    fn distinguishes_split_from_joined_elements() {
+        let split = encode_list(["-a", "b"].into_iter());
+        let joined = encode_list(["-a b"].into_iter());
+        assert_ne!(split, joined);
+    }
+
+    #[test]
+    fn distinguishes_elements_containing_the_delimiter() {
+        let without_delimiter = encode_list(["a", "b"].into_iter());
+        let with_delimiter = encode_list(["a;b"].into_iter());
+        assert_ne!(without_delimiter, with_delimiter);
+    }
+
+    #[test]
+    fn is_deterministic_for_the_same_input() {
+        let items = ["-DFOO=1", "-DBAR=2"];
+        assert_eq!(
+            encode_list(items.into_iter()),
+            encode_list(items.into_iter())
+        );
+    }
+}
+
+///
+/// A stamp file recording the hash of the inputs that produced the current install.
+///
+pub struct HashStamp {
+    path: PathBuf,
+    hash: String,
+}
+
+impl HashStamp {
+    ///
+    /// Creates a stamp at `llvm_build_final/.llvm-build-stamp` for the given `inputs`.
+    ///
+    pub fn new(llvm_build_final: &Path, inputs: &BuildInputs) -> Self {
+        Self {
+            path: llvm_build_final.join(STAMP_FILE_NAME),
+            hash: inputs.hash(),
+        }
+    }
+
+    ///
+    /// Checks whether the on-disk stamp matches this run's inputs and the install
+    /// prefix still exists. `force` (resolved from a CLI flag or the
+    /// `ZKEVM_LLVM_FORCE_REBUILD` environment variable by `resolve_force`) always
+    /// forces a rebuild.
+    ///
+    pub fn is_up_to_date(&self, llvm_target_final: &Path, force: bool) -> bool {
+        if force {
+            return false;
+        }
+        if !llvm_target_final.exists() {
+            return false;
+        }
+        std::fs::read_to_string(self.path.as_path())
+            .map(|existing| existing.trim() == self.hash)
+            .unwrap_or(false)
+    }
+
+    ///
+    /// Writes the stamp to disk after a successful `ninja install`.
+    ///
+    pub fn write(&self) -> anyhow::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(self.path.as_path(), self.hash.as_str())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+//!
+//! The LLVM build strategy selection.
+//!
+
+use std::env;
+use std::path::Path;
+use std::path::PathBuf;
+
+use sha2::Digest;
+use sha2::Sha256;
+
+/// The environment variable selecting the build strategy.
+pub const STRATEGY_ENV_VAR: &str = "ZKEVM_LLVM_STRATEGY";
+
+/// The environment variable overriding the prebuilt release base URL.
+pub const RELEASE_BASE_URL_ENV_VAR: &str = "ZKEVM_LLVM_RELEASE_BASE_URL";
+
+/// The default prebuilt release base URL.
+pub const DEFAULT_RELEASE_BASE_URL: &str =
+    "https://github.com/matter-labs/era-compiler-llvm/releases/download";
+
+///
+/// The LLVM build strategy.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildStrategy {
+    /// Compile LLVM from source with `cmake`/`ninja`.
+    Source,
+    /// Download a prebuilt LLVM artifact for the host triple.
+    Download,
+}
+
+impl BuildStrategy {
+    ///
+    /// Resolves the strategy from the `ZKEVM_LLVM_STRATEGY` environment variable,
+    /// defaulting to `Source` when it is unset or unrecognized.
+    ///
+    /// A CLI `--strategy` flag takes precedence when provided.
+    ///
+    pub fn resolve(cli_flag: Option<&str>) -> Self {
+        let env_value = env::var(STRATEGY_ENV_VAR).unwrap_or_default();
+        match cli_flag.unwrap_or(env_value.as_str()) {
+            "download" => Self::Download,
+            _ => Self::Source,
+        }
+    }
+}
+
+///
+/// Attempts to satisfy the build by downloading a prebuilt LLVM artifact for
+/// `host_triple` matching the reference pinned in `LLVM.lock`, verifying it
+/// against the lock's `sha256` before unpacking it into `target_final`.
+///
+/// Returns `Ok(true)` if a matching, checksum-verified artifact was found and
+/// installed, or `Ok(false)` if the caller should fall back to a source build.
+///
+pub fn try_download_prebuilt(host_triple: &str, target_final: &Path) -> anyhow::Result<bool> {
+    let lock = match crate::build_stamp::load_lock() {
+        Ok(lock) => lock,
+        Err(error) => {
+            println!("\tCould not resolve LLVM.lock, falling back to source build: {error}");
+            return Ok(false);
+        }
+    };
+    let reference = lock.r#ref.clone().unwrap_or_else(|| lock.branch.clone());
+    let expected_sha256 = match lock.sha256.as_deref() {
+        Some(sha256) => sha256.to_lowercase(),
+        None => {
+            println!(
+                "\tLLVM.lock has no `sha256` to verify a prebuilt artifact against, falling back to source build."
+            );
+            return Ok(false);
+        }
+    };
+
+    let base_url = env::var(RELEASE_BASE_URL_ENV_VAR)
+        .unwrap_or_else(|_| DEFAULT_RELEASE_BASE_URL.to_string());
+    let archive_name = format!("llvm-{reference}-{host_triple}.tar.zst");
+    let archive_url = format!("{base_url}/{reference}/{archive_name}");
+
+    let download_directory = crate::LLVMPath::DIRECTORY_LLVM_TARGET;
+    if crate::utils::download(archive_url.as_str(), download_directory).is_err() {
+        println!("\tNo prebuilt artifact found at {archive_url}, falling back to source build.");
+        return Ok(false);
+    }
+
+    let archive_path = PathBuf::from(download_directory).join(archive_name.as_str());
+    verify_checksum(archive_path.as_path(), expected_sha256.as_str())?;
+
+    std::fs::create_dir_all(target_final)?;
+    crate::utils::unpack_tar(archive_path, target_final.to_string_lossy().as_ref())?;
+
+    Ok(true)
+}
+
+///
+/// Verifies that the SHA-256 digest of the file at `archive_path` matches
+/// `expected_sha256` (already lowercased), bailing with a descriptive error on
+/// mismatch. Split out from `try_download_prebuilt` so the security-relevant
+/// comparison can be exercised without a real download.
+///
+pub fn verify_checksum(archive_path: &Path, expected_sha256: &str) -> anyhow::Result<()> {
+    let actual_sha256 = sha256_of_file(archive_path)?;
+    if actual_sha256 != expected_sha256 {
+        anyhow::bail!(
+            "Checksum mismatch for {}: LLVM.lock expects {expected_sha256}, got {actual_sha256}",
+            archive_path.display()
+        );
+    }
+    Ok(())
+}
+
+///
+/// Computes the SHA-256 digest of a file as a lowercase hex string.
+///
+fn sha256_of_file(path: &Path) -> anyhow::Result<String> {
+    let bytes = std::fs::read(path)?;
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    Ok(hex::encode(hasher.finalize()))
+}
@@ -0,0 +1,32 @@
+//!
+//! The LLVM CMake build type.
+//!
+
+use std::fmt;
+
+///
+/// The CMake build type, forwarded to `-DCMAKE_BUILD_TYPE`.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildType {
+    /// Unoptimized, with debug info.
+    Debug,
+    /// Optimized, without debug info.
+    Release,
+    /// Optimized, with debug info.
+    RelWithDebInfo,
+    /// Optimized for size.
+    MinSizeRel,
+}
+
+impl fmt::Display for BuildType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            Self::Debug => "Debug",
+            Self::Release => "Release",
+            Self::RelWithDebInfo => "RelWithDebInfo",
+            Self::MinSizeRel => "MinSizeRel",
+        };
+        write!(f, "{value}")
+    }
+}
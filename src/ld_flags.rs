@@ -0,0 +1,44 @@
+//!
+//! The linker flags threaded into the CMake invocation.
+//!
+
+///
+/// Typed linker flags, translated into the `CMAKE_*_LINKER_FLAGS` CMake variables
+/// so callers can set `-rpath`, `--threads`, or static libc++ options as structured
+/// fields instead of splicing them into `extra_args`.
+///
+#[derive(Debug, Clone, Default)]
+pub struct LdFlags {
+    /// Flags passed to `-DCMAKE_EXE_LINKER_FLAGS`.
+    pub exe: Vec<String>,
+    /// Flags passed to `-DCMAKE_SHARED_LINKER_FLAGS`.
+    pub shared: Vec<String>,
+    /// Flags passed to `-DCMAKE_MODULE_LINKER_FLAGS`.
+    pub module: Vec<String>,
+}
+
+impl LdFlags {
+    ///
+    /// Translates the flags into `-D...` CMake arguments, omitting flag kinds with
+    /// no entries.
+    ///
+    pub fn to_cmake_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if !self.exe.is_empty() {
+            args.push(format!("-DCMAKE_EXE_LINKER_FLAGS='{}'", self.exe.join(" ")));
+        }
+        if !self.shared.is_empty() {
+            args.push(format!(
+                "-DCMAKE_SHARED_LINKER_FLAGS='{}'",
+                self.shared.join(" ")
+            ));
+        }
+        if !self.module.is_empty() {
+            args.push(format!(
+                "-DCMAKE_MODULE_LINKER_FLAGS='{}'",
+                self.module.join(" ")
+            ));
+        }
+        args
+    }
+}
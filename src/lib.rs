@@ -0,0 +1,17 @@
+//!
+//! The LLVM builder library.
+//!
+
+pub mod build_container;
+pub mod build_dispatch;
+pub mod build_stamp;
+pub mod build_strategy;
+pub mod build_type;
+pub mod ld_flags;
+pub mod llvm_path;
+pub mod lock;
+pub mod platforms;
+pub mod utils;
+
+pub use llvm_path::LLVMPath;
+pub use lock::Lock;
@@ -0,0 +1,37 @@
+//!
+//! The well-known LLVM directory paths used throughout the build.
+//!
+
+use std::path::PathBuf;
+
+///
+/// Resolves the directories the LLVM source, build, and install artifacts live
+/// in, all rooted at the current working directory.
+///
+pub struct LLVMPath;
+
+impl LLVMPath {
+    /// The directory prebuilt archives and `musl` sources are downloaded into.
+    pub const DIRECTORY_LLVM_TARGET: &'static str = "target-llvm/";
+
+    /// The checked-out LLVM source module's `llvm` subdirectory, i.e. the CMake
+    /// source directory passed to `-S`.
+    pub fn llvm_module_llvm() -> anyhow::Result<PathBuf> {
+        crate::utils::absolute_path("llvm-project/llvm")
+    }
+
+    /// The directory `cmake`/`ninja` build artifacts are written into.
+    pub fn llvm_build_final() -> anyhow::Result<PathBuf> {
+        crate::utils::absolute_path(format!("{}build-final", Self::DIRECTORY_LLVM_TARGET))
+    }
+
+    /// The directory the built LLVM toolchain is installed into.
+    pub fn llvm_target_final() -> anyhow::Result<PathBuf> {
+        crate::utils::absolute_path(format!("{}target-final", Self::DIRECTORY_LLVM_TARGET))
+    }
+
+    /// The path to a downloaded `musl` source tarball named `tar_file_name`.
+    pub fn musl_source(tar_file_name: &str) -> anyhow::Result<PathBuf> {
+        crate::utils::absolute_path(format!("{}{tar_file_name}", Self::DIRECTORY_LLVM_TARGET))
+    }
+}
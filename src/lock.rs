@@ -0,0 +1,24 @@
+//!
+//! The `LLVM.lock` file format.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// Pins the LLVM source revision to check out and, for the `download` build
+/// strategy, the expected checksum of the prebuilt artifact.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Lock {
+    /// The LLVM repository URL.
+    pub url: String,
+    /// The LLVM repository branch.
+    pub branch: String,
+    /// The pinned commit, if more specific than the branch.
+    pub r#ref: Option<String>,
+    /// The expected SHA-256 checksum of the prebuilt artifact fetched by the
+    /// `download` build strategy.
+    #[serde(default)]
+    pub sha256: Option<String>,
+}
@@ -5,10 +5,17 @@
 use std::collections::HashSet;
 use std::process::Command;
 
+use crate::build_stamp::BuildInputs;
+use crate::build_stamp::HashStamp;
+use crate::build_strategy::BuildStrategy;
 use crate::build_type::BuildType;
+use crate::ld_flags::LdFlags;
 use crate::llvm_path::LLVMPath;
 use crate::platforms::Platform;
 
+/// The host triple of this builder, used to locate prebuilt artifacts.
+const HOST_TRIPLE: &str = "aarch64-apple-darwin";
+
 ///
 /// The building sequence.
 ///
@@ -20,14 +27,45 @@ pub fn build(
     extra_args: Vec<String>,
     use_ccache: bool,
     enable_assertions: bool,
+    strategy: Option<&str>,
+    ld_flags: &LdFlags,
+    force: bool,
 ) -> anyhow::Result<()> {
+    let llvm_target_final = LLVMPath::llvm_target_final()?;
+    let llvm_build_final = LLVMPath::llvm_build_final()?;
+    let llvm_module_llvm = LLVMPath::llvm_module_llvm()?;
+
+    if BuildStrategy::resolve(strategy) == BuildStrategy::Download
+        && crate::build_strategy::try_download_prebuilt(HOST_TRIPLE, llvm_target_final.as_path())?
+    {
+        return Ok(());
+    }
+
+    let lock = crate::build_stamp::load_lock()?;
+    let stamp = HashStamp::new(
+        llvm_build_final.as_path(),
+        &BuildInputs {
+            url: lock.url.as_str(),
+            branch: lock.branch.as_str(),
+            reference: lock.r#ref.as_deref(),
+            build_type,
+            targets: &targets,
+            enable_tests,
+            enable_coverage,
+            enable_assertions,
+            use_ccache,
+            extra_args: extra_args.as_slice(),
+            ld_flags,
+        },
+    );
+    if stamp.is_up_to_date(llvm_target_final.as_path(), crate::build_stamp::resolve_force(force)) {
+        println!("\tLLVM build is up to date; skipping.");
+        return Ok(());
+    }
+
     crate::utils::check_presence("cmake")?;
     crate::utils::check_presence("ninja")?;
 
-    let llvm_module_llvm = LLVMPath::llvm_module_llvm()?;
-    let llvm_build_final = LLVMPath::llvm_build_final()?;
-    let llvm_target_final = LLVMPath::llvm_target_final()?;
-
     crate::utils::command(
         Command::new("cmake")
             .args([
@@ -55,6 +93,9 @@ pub fn build(
                 "-DLLVM_ENABLE_PROJECTS='lld'",
                 "-DCMAKE_OSX_DEPLOYMENT_TARGET='11.0'",
             ])
+            .args(crate::platforms::shared::shared_build_opts_ld_flags(
+                ld_flags,
+            ))
             .args(crate::platforms::shared::shared_build_opts_tests(
                 enable_tests,
             ))
@@ -75,6 +116,7 @@ pub fn build(
     )?;
 
     crate::utils::ninja(llvm_build_final.as_ref())?;
+    stamp.write()?;
 
     Ok(())
 }
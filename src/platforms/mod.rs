@@ -0,0 +1,33 @@
+//!
+//! The LLVM target platforms.
+//!
+
+pub mod aarch64_macos;
+pub mod shared;
+
+use std::fmt;
+
+///
+/// An LLVM backend target to build into `LLVM_TARGETS_TO_BUILD`, not to be
+/// confused with the host/cross triple the builder itself runs on.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Platform {
+    /// The `X86` backend.
+    X86,
+    /// The `AArch64` backend.
+    AArch64,
+    /// The `RISCV` backend.
+    RISCV,
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            Self::X86 => "X86",
+            Self::AArch64 => "AArch64",
+            Self::RISCV => "RISCV",
+        };
+        write!(f, "{value}")
+    }
+}
@@ -0,0 +1,76 @@
+//!
+//! CMake build options shared across platform builders.
+//!
+
+/// Options shared by every platform build.
+pub const SHARED_BUILD_OPTS: [&str; 2] = [
+    "-DLLVM_ENABLE_TERMINFO=OFF",
+    "-DLLVM_ENABLE_ZLIB=OFF",
+];
+
+/// Options shared by every non-`musl` platform build.
+pub const SHARED_BUILD_OPTS_NOT_MUSL: [&str; 1] = ["-DLLVM_ENABLE_LIBXML2=OFF"];
+
+///
+/// The CMake options enabling LLVM's own test suite and examples.
+///
+pub fn shared_build_opts_tests(enable_tests: bool) -> Vec<&'static str> {
+    if enable_tests {
+        vec!["-DLLVM_BUILD_TESTS=ON", "-DLLVM_BUILD_EXAMPLES=ON"]
+    } else {
+        vec!["-DLLVM_BUILD_TESTS=OFF", "-DLLVM_BUILD_EXAMPLES=OFF"]
+    }
+}
+
+///
+/// The CMake option enabling source-based coverage instrumentation.
+///
+pub fn shared_build_opts_coverage(enable_coverage: bool) -> Vec<&'static str> {
+    if enable_coverage {
+        vec!["-DLLVM_BUILD_INSTRUMENTED_COVERAGE=ON"]
+    } else {
+        Vec::new()
+    }
+}
+
+///
+/// The CMake options enabling a `ccache` compiler launcher.
+///
+pub fn shared_build_opts_ccache(use_ccache: bool) -> Vec<&'static str> {
+    if use_ccache {
+        vec![
+            "-DCMAKE_C_COMPILER_LAUNCHER=ccache",
+            "-DCMAKE_CXX_COMPILER_LAUNCHER=ccache",
+        ]
+    } else {
+        Vec::new()
+    }
+}
+
+///
+/// The CMake option enabling LLVM assertions.
+///
+pub fn shared_build_opts_assertions(enable_assertions: bool) -> Vec<&'static str> {
+    if enable_assertions {
+        vec!["-DLLVM_ENABLE_ASSERTIONS=ON"]
+    } else {
+        vec!["-DLLVM_ENABLE_ASSERTIONS=OFF"]
+    }
+}
+
+///
+/// Suppresses the macOS linker's duplicate-libraries warning triggered by
+/// LLVM's static archive layout.
+///
+pub fn macos_build_opts_ignore_dupicate_libs_warnings() -> Vec<&'static str> {
+    vec!["-DCMAKE_EXE_LINKER_FLAGS=-Wl,-no_warn_duplicate_libraries"]
+}
+
+///
+/// Translates `ld_flags` into the `CMAKE_*_LINKER_FLAGS` CMake arguments, so
+/// every platform builder threads linker flags the same way instead of each
+/// calling `LdFlags::to_cmake_args` directly.
+///
+pub fn shared_build_opts_ld_flags(ld_flags: &crate::ld_flags::LdFlags) -> Vec<String> {
+    ld_flags.to_cmake_args()
+}
@@ -15,6 +15,8 @@ use flate2::read::GzDecoder;
 use path_slash::PathBufExt;
 use regex::Regex;
 use tar::Archive;
+use xz2::read::XzDecoder;
+use zstd::stream::read::Decoder as ZstdDecoder;
 
 /// The LLVM host repository URL.
 pub const LLVM_HOST_SOURCE_URL: &str = "https://github.com/llvm/llvm-project";
@@ -89,11 +91,27 @@ pub fn download(url: &str, path: &str) -> anyhow::Result<()> {
 ///
 /// Unpack a tarball.
 ///
+/// The decoder is selected from the filename extension: `.tar.gz`/`.tgz` use
+/// `GzDecoder`, `.tar.xz`/`.txz` use `XzDecoder`, and `.tar.zst`/`.tzst` use
+/// `ZstdDecoder`. Any other extension is rejected.
+///
 pub fn unpack_tar(filename: PathBuf, path: &str) -> anyhow::Result<()> {
-    let tar_gz = File::open(filename)?;
-    let tar = GzDecoder::new(tar_gz);
-    let mut archive = Archive::new(tar);
-    archive.unpack(path)?;
+    let tar_file = File::open(filename.as_path())?;
+    let name = filename.to_string_lossy();
+
+    if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+        let mut archive = Archive::new(GzDecoder::new(tar_file));
+        archive.unpack(path)?;
+    } else if name.ends_with(".tar.xz") || name.ends_with(".txz") {
+        let mut archive = Archive::new(XzDecoder::new(tar_file));
+        archive.unpack(path)?;
+    } else if name.ends_with(".tar.zst") || name.ends_with(".tzst") {
+        let mut archive = Archive::new(ZstdDecoder::new(tar_file)?);
+        archive.unpack(path)?;
+    } else {
+        anyhow::bail!("Unsupported archive format: {}", name);
+    }
+
     Ok(())
 }
 
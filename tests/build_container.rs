@@ -0,0 +1,22 @@
+//!
+//! Tests for `build_container::find_target`'s supported-triple matrix lookup.
+//!
+
+use compiler_llvm_builder::build_container::find_target;
+
+#[test]
+fn finds_every_supported_triple() {
+    for triple in [
+        "aarch64-unknown-linux-musl",
+        "riscv64-unknown-linux-gnu",
+        "x86_64-unknown-linux-musl",
+    ] {
+        let target = find_target(triple).unwrap_or_else(|| panic!("{triple} must be supported"));
+        assert_eq!(target.triple, triple);
+    }
+}
+
+#[test]
+fn returns_none_for_an_unsupported_triple() {
+    assert!(find_target("i686-pc-windows-msvc").is_none());
+}
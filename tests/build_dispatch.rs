@@ -0,0 +1,59 @@
+//!
+//! Tests for `build_dispatch`'s cross-target-vs-host routing.
+//!
+
+use std::sync::Mutex;
+
+use compiler_llvm_builder::build_container::CROSS_TARGET_ENV_VAR;
+use compiler_llvm_builder::build_dispatch::is_cross_target;
+use compiler_llvm_builder::build_dispatch::resolve_cross_target;
+
+/// Serializes the tests below, since they mutate the process-wide
+/// `ZKEVM_LLVM_CROSS_TARGET` environment variable.
+static ENV_VAR_GUARD: Mutex<()> = Mutex::new(());
+
+#[test]
+fn recognizes_every_supported_cross_target() {
+    for triple in [
+        "aarch64-unknown-linux-musl",
+        "riscv64-unknown-linux-gnu",
+        "x86_64-unknown-linux-musl",
+    ] {
+        assert!(is_cross_target(Some(triple)), "{triple} must be a cross target");
+    }
+}
+
+#[test]
+fn treats_unsupported_or_absent_triples_as_native() {
+    assert!(!is_cross_target(Some("aarch64-apple-darwin")));
+    assert!(!is_cross_target(None));
+}
+
+#[test]
+fn resolve_cross_target_reads_the_env_var_when_no_cli_flag_is_given() {
+    let _guard = ENV_VAR_GUARD.lock().expect("the mutex must not be poisoned");
+    std::env::set_var(CROSS_TARGET_ENV_VAR, "riscv64-unknown-linux-gnu");
+    assert_eq!(
+        resolve_cross_target(None),
+        Some("riscv64-unknown-linux-gnu".to_string())
+    );
+    std::env::remove_var(CROSS_TARGET_ENV_VAR);
+}
+
+#[test]
+fn resolve_cross_target_cli_flag_takes_precedence_over_env() {
+    let _guard = ENV_VAR_GUARD.lock().expect("the mutex must not be poisoned");
+    std::env::set_var(CROSS_TARGET_ENV_VAR, "riscv64-unknown-linux-gnu");
+    assert_eq!(
+        resolve_cross_target(Some("aarch64-unknown-linux-musl")),
+        Some("aarch64-unknown-linux-musl".to_string())
+    );
+    std::env::remove_var(CROSS_TARGET_ENV_VAR);
+}
+
+#[test]
+fn resolve_cross_target_is_none_without_a_flag_or_env_var() {
+    let _guard = ENV_VAR_GUARD.lock().expect("the mutex must not be poisoned");
+    std::env::remove_var(CROSS_TARGET_ENV_VAR);
+    assert_eq!(resolve_cross_target(None), None);
+}
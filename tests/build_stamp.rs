@@ -0,0 +1,100 @@
+//!
+//! Tests for `HashStamp`'s write/`is_up_to_date` round-tripping.
+//!
+
+use std::collections::HashSet;
+
+use assert_fs::fixture::PathChild;
+use assert_fs::TempDir;
+
+use compiler_llvm_builder::build_stamp::BuildInputs;
+use compiler_llvm_builder::build_stamp::HashStamp;
+use compiler_llvm_builder::build_type::BuildType;
+use compiler_llvm_builder::ld_flags::LdFlags;
+use compiler_llvm_builder::platforms::Platform;
+
+/// Builds a `BuildInputs` borrowing from the given `targets` and `ld_flags`,
+/// fixed to otherwise-arbitrary-but-stable values.
+fn build_inputs<'a>(targets: &'a HashSet<Platform>, ld_flags: &'a LdFlags) -> BuildInputs<'a> {
+    BuildInputs {
+        url: "https://github.com/matter-labs/era-compiler-llvm",
+        branch: "v1.4.2",
+        reference: Some("b5ccf6d5774e9bc3cee47ab4a334404718d3adfd"),
+        build_type: BuildType::Release,
+        targets,
+        enable_tests: false,
+        enable_coverage: false,
+        enable_assertions: false,
+        use_ccache: false,
+        extra_args: &[],
+        ld_flags,
+    }
+}
+
+#[test]
+fn is_up_to_date_after_a_matching_write() {
+    let temp_dir = TempDir::new().expect("creating the temp dir must succeed");
+    let llvm_build_final = temp_dir.child("build-final");
+    let llvm_target_final = temp_dir.child("target-final");
+    std::fs::create_dir_all(llvm_target_final.path())
+        .expect("creating the target directory must succeed");
+
+    let targets = HashSet::from([Platform::AArch64]);
+    let ld_flags = LdFlags::default();
+    let stamp = HashStamp::new(llvm_build_final.path(), &build_inputs(&targets, &ld_flags));
+    stamp.write().expect("writing the stamp must succeed");
+
+    assert!(stamp.is_up_to_date(llvm_target_final.path(), false));
+}
+
+#[test]
+fn force_always_requires_a_rebuild() {
+    let temp_dir = TempDir::new().expect("creating the temp dir must succeed");
+    let llvm_build_final = temp_dir.child("build-final");
+    let llvm_target_final = temp_dir.child("target-final");
+    std::fs::create_dir_all(llvm_target_final.path())
+        .expect("creating the target directory must succeed");
+
+    let targets = HashSet::from([Platform::AArch64]);
+    let ld_flags = LdFlags::default();
+    let stamp = HashStamp::new(llvm_build_final.path(), &build_inputs(&targets, &ld_flags));
+    stamp.write().expect("writing the stamp must succeed");
+
+    assert!(!stamp.is_up_to_date(llvm_target_final.path(), true));
+}
+
+#[test]
+fn requires_a_rebuild_when_the_target_directory_is_missing() {
+    let temp_dir = TempDir::new().expect("creating the temp dir must succeed");
+    let llvm_build_final = temp_dir.child("build-final");
+    let llvm_target_final = temp_dir.child("target-final");
+
+    let targets = HashSet::from([Platform::AArch64]);
+    let ld_flags = LdFlags::default();
+    let stamp = HashStamp::new(llvm_build_final.path(), &build_inputs(&targets, &ld_flags));
+    stamp.write().expect("writing the stamp must succeed");
+
+    assert!(!stamp.is_up_to_date(llvm_target_final.path(), false));
+}
+
+#[test]
+fn requires_a_rebuild_when_the_inputs_changed_since_the_stamp_was_written() {
+    let temp_dir = TempDir::new().expect("creating the temp dir must succeed");
+    let llvm_build_final = temp_dir.child("build-final");
+    let llvm_target_final = temp_dir.child("target-final");
+    std::fs::create_dir_all(llvm_target_final.path())
+        .expect("creating the target directory must succeed");
+
+    let targets = HashSet::from([Platform::AArch64]);
+    let ld_flags = LdFlags::default();
+    let stale_stamp = HashStamp::new(llvm_build_final.path(), &build_inputs(&targets, &ld_flags));
+    stale_stamp.write().expect("writing the stamp must succeed");
+
+    let changed_targets = HashSet::from([Platform::AArch64, Platform::RISCV]);
+    let current_stamp = HashStamp::new(
+        llvm_build_final.path(),
+        &build_inputs(&changed_targets, &ld_flags),
+    );
+
+    assert!(!current_stamp.is_up_to_date(llvm_target_final.path(), false));
+}
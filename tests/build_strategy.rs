@@ -0,0 +1,124 @@
+//!
+//! Tests for `BuildStrategy::resolve`'s CLI-flag-then-env precedence and
+//! `try_download_prebuilt`'s checksum verification.
+//!
+
+use std::sync::Mutex;
+
+use assert_fs::fixture::PathChild;
+use assert_fs::fixture::FileWriteStr;
+use assert_fs::TempDir;
+
+use compiler_llvm_builder::build_strategy::try_download_prebuilt;
+use compiler_llvm_builder::build_strategy::verify_checksum;
+use compiler_llvm_builder::build_strategy::BuildStrategy;
+use compiler_llvm_builder::build_strategy::STRATEGY_ENV_VAR;
+
+mod common;
+
+/// Serializes the tests below, since they all mutate the process-wide
+/// `ZKEVM_LLVM_STRATEGY` environment variable and `cargo test` runs tests in the
+/// same binary on parallel threads by default.
+static ENV_VAR_GUARD: Mutex<()> = Mutex::new(());
+
+/// Serializes the tests that change the process-wide current directory to make
+/// `load_lock`'s relative `LLVM.lock` read see a test fixture.
+static CWD_GUARD: Mutex<()> = Mutex::new(());
+
+#[test]
+fn defaults_to_source_when_unset() {
+    let _guard = ENV_VAR_GUARD.lock().expect("the mutex must not be poisoned");
+    std::env::remove_var(STRATEGY_ENV_VAR);
+    assert_eq!(BuildStrategy::resolve(None), BuildStrategy::Source);
+}
+
+#[test]
+fn reads_download_from_env() {
+    let _guard = ENV_VAR_GUARD.lock().expect("the mutex must not be poisoned");
+    std::env::set_var(STRATEGY_ENV_VAR, "download");
+    assert_eq!(BuildStrategy::resolve(None), BuildStrategy::Download);
+    std::env::remove_var(STRATEGY_ENV_VAR);
+}
+
+#[test]
+fn ignores_unrecognized_env_value() {
+    let _guard = ENV_VAR_GUARD.lock().expect("the mutex must not be poisoned");
+    std::env::set_var(STRATEGY_ENV_VAR, "bogus");
+    assert_eq!(BuildStrategy::resolve(None), BuildStrategy::Source);
+    std::env::remove_var(STRATEGY_ENV_VAR);
+}
+
+#[test]
+fn cli_flag_takes_precedence_over_env() {
+    let _guard = ENV_VAR_GUARD.lock().expect("the mutex must not be poisoned");
+    std::env::set_var(STRATEGY_ENV_VAR, "download");
+    assert_eq!(BuildStrategy::resolve(Some("source")), BuildStrategy::Source);
+    std::env::remove_var(STRATEGY_ENV_VAR);
+}
+
+#[test]
+fn verify_checksum_accepts_a_matching_digest() {
+    let temp_dir = TempDir::new().expect("creating the temp dir must succeed");
+    let archive = temp_dir.child("archive.tar.zst");
+    archive
+        .write_str("prebuilt archive contents")
+        .expect("writing the archive must succeed");
+
+    let expected_sha256 = sha256_hex("prebuilt archive contents");
+    assert!(verify_checksum(archive.path(), expected_sha256.as_str()).is_ok());
+}
+
+#[test]
+fn verify_checksum_rejects_a_mismatching_digest() {
+    let temp_dir = TempDir::new().expect("creating the temp dir must succeed");
+    let archive = temp_dir.child("archive.tar.zst");
+    archive
+        .write_str("prebuilt archive contents")
+        .expect("writing the archive must succeed");
+
+    let result = verify_checksum(archive.path(), common::ERA_LLVM_REPO_TEST_SHA_INVALID);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn try_download_prebuilt_falls_back_to_source_without_a_lock_sha256() {
+    let _env_guard = ENV_VAR_GUARD.lock().expect("the mutex must not be poisoned");
+    let _cwd_guard = CWD_GUARD.lock().expect("the mutex must not be poisoned");
+
+    let original_dir = std::env::current_dir().expect("reading the current dir must succeed");
+    let temp_dir = TempDir::new().expect("creating the temp dir must succeed");
+    temp_dir
+        .child(common::LLVM_LOCK_FILE)
+        .write_str(
+            toml::to_string(&compiler_llvm_builder::Lock {
+                url: common::ERA_LLVM_REPO_URL.to_string(),
+                branch: common::ERA_LLVM_REPO_TEST_BRANCH.to_string(),
+                r#ref: Some(common::ERA_LLVM_REPO_TEST_REF.to_string()),
+                sha256: None,
+            })
+            .expect("serializing the lock must succeed")
+            .as_str(),
+        )
+        .expect("writing the lock file must succeed");
+    std::env::set_current_dir(temp_dir.path()).expect("changing the current dir must succeed");
+
+    let result = try_download_prebuilt(
+        "x86_64-unknown-linux-gnu",
+        temp_dir.child("target-final").path(),
+    );
+
+    std::env::set_current_dir(original_dir.as_path())
+        .expect("restoring the current dir must succeed");
+
+    assert_eq!(result.expect("must not error"), false);
+}
+
+/// Computes the SHA-256 digest of `data` as a lowercase hex string, matching
+/// `try_download_prebuilt`'s own digest format.
+fn sha256_hex(data: &str) -> String {
+    use sha2::Digest;
+    let mut hasher = sha2::Sha256::new();
+    hasher.update(data.as_bytes());
+    hex::encode(hasher.finalize())
+}
@@ -10,11 +10,21 @@ pub const LLVM_LOCK_FILE: &str = "LLVM.lock";
 
 /// Creates a temporary lock file for testing.
 pub fn create_test_tmp_lockfile(reference: &str) -> anyhow::Result<assert_fs::NamedTempFile> {
+    create_test_tmp_lockfile_with_sha256(reference, None)
+}
+
+/// Creates a temporary lock file for testing, pinning `sha256` as the expected
+/// checksum of the prebuilt artifact (or leaving it unset when `None`).
+pub fn create_test_tmp_lockfile_with_sha256(
+    reference: &str,
+    sha256: Option<&str>,
+) -> anyhow::Result<assert_fs::NamedTempFile> {
     let file = assert_fs::NamedTempFile::new(LLVM_LOCK_FILE)?;
     let lock = compiler_llvm_builder::Lock {
         url: ERA_LLVM_REPO_URL.to_string(),
         branch: ERA_LLVM_REPO_TEST_BRANCH.to_string(),
         r#ref: Some(reference.to_string()),
+        sha256: sha256.map(str::to_string),
     };
     file.write_str(toml::to_string(&lock)?.as_str())?;
     Ok(file)
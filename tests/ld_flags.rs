@@ -0,0 +1,45 @@
+//!
+//! Tests for `LdFlags::to_cmake_args` formatting.
+//!
+
+use compiler_llvm_builder::ld_flags::LdFlags;
+
+#[test]
+fn empty_flags_produce_no_args() {
+    let ld_flags = LdFlags::default();
+    assert!(ld_flags.to_cmake_args().is_empty());
+}
+
+#[test]
+fn each_flag_kind_maps_to_its_own_cmake_variable() {
+    let ld_flags = LdFlags {
+        exe: vec!["-rpath".to_string(), "/opt/lib".to_string()],
+        shared: vec!["--threads".to_string()],
+        module: vec!["-static-libstdc++".to_string()],
+    };
+
+    let args = ld_flags.to_cmake_args();
+
+    assert_eq!(
+        args,
+        vec![
+            "-DCMAKE_EXE_LINKER_FLAGS='-rpath /opt/lib'",
+            "-DCMAKE_SHARED_LINKER_FLAGS='--threads'",
+            "-DCMAKE_MODULE_LINKER_FLAGS='-static-libstdc++'",
+        ]
+    );
+}
+
+#[test]
+fn omits_variables_for_empty_flag_kinds() {
+    let ld_flags = LdFlags {
+        exe: vec!["-rpath".to_string()],
+        shared: Vec::new(),
+        module: Vec::new(),
+    };
+
+    assert_eq!(
+        ld_flags.to_cmake_args(),
+        vec!["-DCMAKE_EXE_LINKER_FLAGS='-rpath'"]
+    );
+}
@@ -0,0 +1,89 @@
+//!
+//! Tests for `unpack_tar`'s extension-based decoder dispatch.
+//!
+
+use std::io::Write;
+
+use assert_fs::fixture::PathChild;
+use assert_fs::TempDir;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+/// Builds a minimal in-memory tar archive containing a single `hello.txt` entry.
+fn tar_bytes() -> Vec<u8> {
+    let mut tar_bytes = Vec::new();
+    {
+        let mut builder = tar::Builder::new(&mut tar_bytes);
+        let data = b"hello";
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, "hello.txt", &data[..])
+            .expect("appending the tar entry must succeed");
+        builder.finish().expect("finishing the tar archive must succeed");
+    }
+    tar_bytes
+}
+
+fn assert_unpacks_to_hello(temp_dir: &TempDir, archive_name: &str, archive_bytes: Vec<u8>) {
+    let archive_path = temp_dir.child(archive_name);
+    std::fs::write(archive_path.path(), archive_bytes).expect("writing the archive must succeed");
+
+    compiler_llvm_builder::utils::unpack_tar(
+        archive_path.path().to_path_buf(),
+        temp_dir.path().to_str().expect("temp dir path must be valid UTF-8"),
+    )
+    .expect("unpack_tar must succeed for a supported extension");
+
+    let unpacked = std::fs::read_to_string(temp_dir.child("hello.txt").path())
+        .expect("the archived file must have been unpacked");
+    assert_eq!(unpacked, "hello");
+}
+
+#[test]
+fn unpacks_tar_gz() {
+    let temp_dir = TempDir::new().expect("creating the temp dir must succeed");
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(tar_bytes().as_slice())
+        .expect("gzip-encoding the tar archive must succeed");
+    let archive_bytes = encoder.finish().expect("finishing the gzip stream must succeed");
+
+    assert_unpacks_to_hello(&temp_dir, "archive.tar.gz", archive_bytes);
+}
+
+#[test]
+fn unpacks_tar_xz() {
+    let temp_dir = TempDir::new().expect("creating the temp dir must succeed");
+    let mut encoder = xz2::write::XzEncoder::new(Vec::new(), 6);
+    encoder
+        .write_all(tar_bytes().as_slice())
+        .expect("xz-encoding the tar archive must succeed");
+    let archive_bytes = encoder.finish().expect("finishing the xz stream must succeed");
+
+    assert_unpacks_to_hello(&temp_dir, "archive.tar.xz", archive_bytes);
+}
+
+#[test]
+fn unpacks_tar_zst() {
+    let temp_dir = TempDir::new().expect("creating the temp dir must succeed");
+    let archive_bytes = zstd::stream::encode_all(tar_bytes().as_slice(), 0)
+        .expect("zstd-encoding the tar archive must succeed");
+
+    assert_unpacks_to_hello(&temp_dir, "archive.tar.zst", archive_bytes);
+}
+
+#[test]
+fn rejects_unknown_extension() {
+    let temp_dir = TempDir::new().expect("creating the temp dir must succeed");
+    let archive_path = temp_dir.child("archive.rar");
+    std::fs::write(archive_path.path(), b"not a tarball").expect("writing the file must succeed");
+
+    let result = compiler_llvm_builder::utils::unpack_tar(
+        archive_path.path().to_path_buf(),
+        temp_dir.path().to_str().expect("temp dir path must be valid UTF-8"),
+    );
+
+    assert!(result.is_err());
+}